@@ -0,0 +1,37 @@
+//! Delay
+
+use embedded_hal::blocking::delay::{DelayMs, DelayUs};
+use std::{thread::sleep, time::Duration};
+
+/// Blocking delay, implemented with [`std::thread::sleep`].
+///
+/// This is a host-side delay, not driven by the FTDI device, since
+/// libftd2xx has no API for timing on the device itself.
+#[derive(Debug, Default)]
+pub struct Delay;
+
+impl Delay {
+    /// Create a new `Delay`.
+    pub fn new() -> Delay {
+        Delay
+    }
+}
+
+impl DelayMs<u32> for Delay {
+    fn delay_ms(&mut self, ms: u32) {
+        sleep(Duration::from_millis(u64::from(ms)))
+    }
+}
+
+impl DelayUs<u32> for Delay {
+    fn delay_us(&mut self, us: u32) {
+        sleep(Duration::from_micros(u64::from(us)))
+    }
+}
+
+#[cfg(feature = "eh1")]
+impl eh1::delay::DelayNs for Delay {
+    fn delay_ns(&mut self, ns: u32) {
+        sleep(Duration::from_nanos(u64::from(ns)))
+    }
+}
@@ -0,0 +1,36 @@
+//! Error type for the `eh1` embedded-hal 1.0 trait implementations.
+//!
+//! The 1.0 traits require their associated `Error` to implement the
+//! `{digital,spi,i2c}::Error` marker traits. Neither those traits nor
+//! [`TimeoutError`] are local to this crate, so they can't be implemented
+//! directly on it; this newtype gives them somewhere to live instead.
+
+use libftd2xx::TimeoutError;
+
+/// Error type returned by the `eh1` embedded-hal 1.0 trait implementations.
+#[derive(Debug)]
+pub struct Error(pub TimeoutError);
+
+impl From<TimeoutError> for Error {
+    fn from(err: TimeoutError) -> Error {
+        Error(err)
+    }
+}
+
+impl eh1::digital::Error for Error {
+    fn kind(&self) -> eh1::digital::ErrorKind {
+        eh1::digital::ErrorKind::Other
+    }
+}
+
+impl eh1::spi::Error for Error {
+    fn kind(&self) -> eh1::spi::ErrorKind {
+        eh1::spi::ErrorKind::Other
+    }
+}
+
+impl eh1::i2c::Error for Error {
+    fn kind(&self) -> eh1::i2c::ErrorKind {
+        eh1::i2c::ErrorKind::Other
+    }
+}
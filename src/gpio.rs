@@ -0,0 +1,146 @@
+//! GPIO
+
+use super::{Bank, FtInner, PinUse};
+use embedded_hal::digital::v2::{InputPin as _InputPin, OutputPin as _OutputPin};
+use libftd2xx::{FtdiMpsse, TimeoutError};
+use std::{cell::RefCell, sync::Mutex};
+
+/// FTDI output pin.
+///
+/// This is created by calling [`FtHal::ad0`]-[`FtHal::ad7`] or
+/// [`FtHal::ac0`]-[`FtHal::ac7`].
+///
+/// [`FtHal::ad0`]: crate::FtHal::ad0
+/// [`FtHal::ad7`]: crate::FtHal::ad7
+/// [`FtHal::ac0`]: crate::FtHal::ac0
+/// [`FtHal::ac7`]: crate::FtHal::ac7
+#[derive(Debug)]
+pub struct OutputPin<'a, DEVICE> {
+    mtx: &'a Mutex<RefCell<FtInner<DEVICE>>>,
+    bank: Bank,
+    idx: u8,
+}
+
+impl<'a, DEVICE: FtdiMpsse> OutputPin<'a, DEVICE> {
+    pub(crate) fn new(
+        mtx: &'a Mutex<RefCell<FtInner<DEVICE>>>,
+        bank: Bank,
+        idx: u8,
+    ) -> OutputPin<'a, DEVICE> {
+        let lock = mtx.lock().expect("Failed to aquire FTDI mutex");
+        let mut inner = lock.borrow_mut();
+        inner.allocate_pin(bank, idx, PinUse::Output);
+        inner.direction[bank as usize] |= 1 << idx;
+        inner
+            .set_gpio(bank)
+            .expect("Failed to set GPIO direction");
+
+        OutputPin { mtx, bank, idx }
+    }
+
+    /// Bank this pin belongs to.
+    pub(crate) fn bank(&self) -> Bank {
+        self.bank
+    }
+
+    /// Index of this pin within its bank.
+    pub(crate) fn idx(&self) -> u8 {
+        self.idx
+    }
+}
+
+impl<'a, DEVICE: FtdiMpsse> _OutputPin for OutputPin<'a, DEVICE> {
+    type Error = TimeoutError;
+
+    fn set_low(&mut self) -> Result<(), Self::Error> {
+        let lock = self.mtx.lock().expect("Failed to aquire FTDI mutex");
+        let mut inner = lock.borrow_mut();
+        inner.value[self.bank as usize] &= !(1 << self.idx);
+        inner.set_gpio(self.bank)
+    }
+
+    fn set_high(&mut self) -> Result<(), Self::Error> {
+        let lock = self.mtx.lock().expect("Failed to aquire FTDI mutex");
+        let mut inner = lock.borrow_mut();
+        inner.value[self.bank as usize] |= 1 << self.idx;
+        inner.set_gpio(self.bank)
+    }
+}
+
+/// FTDI input pin.
+///
+/// This is created by calling [`FtHal::ad_input`] or [`FtHal::ac_input`].
+///
+/// [`FtHal::ad_input`]: crate::FtHal::ad_input
+/// [`FtHal::ac_input`]: crate::FtHal::ac_input
+#[derive(Debug)]
+pub struct InputPin<'a, DEVICE> {
+    mtx: &'a Mutex<RefCell<FtInner<DEVICE>>>,
+    bank: Bank,
+    idx: u8,
+}
+
+impl<'a, DEVICE: FtdiMpsse> InputPin<'a, DEVICE> {
+    pub(crate) fn new(
+        mtx: &'a Mutex<RefCell<FtInner<DEVICE>>>,
+        bank: Bank,
+        idx: u8,
+    ) -> InputPin<'a, DEVICE> {
+        let lock = mtx.lock().expect("Failed to aquire FTDI mutex");
+        let mut inner = lock.borrow_mut();
+        inner.allocate_pin(bank, idx, PinUse::Input);
+        inner.direction[bank as usize] &= !(1 << idx);
+        inner
+            .set_gpio(bank)
+            .expect("Failed to set GPIO direction");
+
+        InputPin { mtx, bank, idx }
+    }
+}
+
+impl<'a, DEVICE: FtdiMpsse> _InputPin for InputPin<'a, DEVICE> {
+    type Error = TimeoutError;
+
+    fn is_high(&self) -> Result<bool, Self::Error> {
+        Ok(!self.is_low()?)
+    }
+
+    fn is_low(&self) -> Result<bool, Self::Error> {
+        let lock = self.mtx.lock().expect("Failed to aquire FTDI mutex");
+        let mut inner = lock.borrow_mut();
+        let pins = inner.gpio(self.bank)?;
+        Ok(pins & (1 << self.idx) == 0)
+    }
+}
+
+#[cfg(feature = "eh1")]
+impl<'a, DEVICE: FtdiMpsse> eh1::digital::ErrorType for OutputPin<'a, DEVICE> {
+    type Error = crate::Eh1Error;
+}
+
+#[cfg(feature = "eh1")]
+impl<'a, DEVICE: FtdiMpsse> eh1::digital::OutputPin for OutputPin<'a, DEVICE> {
+    fn set_low(&mut self) -> Result<(), Self::Error> {
+        Ok(_OutputPin::set_low(self)?)
+    }
+
+    fn set_high(&mut self) -> Result<(), Self::Error> {
+        Ok(_OutputPin::set_high(self)?)
+    }
+}
+
+#[cfg(feature = "eh1")]
+impl<'a, DEVICE: FtdiMpsse> eh1::digital::ErrorType for InputPin<'a, DEVICE> {
+    type Error = crate::Eh1Error;
+}
+
+#[cfg(feature = "eh1")]
+impl<'a, DEVICE: FtdiMpsse> eh1::digital::InputPin for InputPin<'a, DEVICE> {
+    fn is_high(&mut self) -> Result<bool, Self::Error> {
+        Ok(_InputPin::is_high(self)?)
+    }
+
+    fn is_low(&mut self) -> Result<bool, Self::Error> {
+        Ok(_InputPin::is_low(self)?)
+    }
+}
@@ -0,0 +1,244 @@
+//! I2C
+//!
+//! There is no dedicated I2C engine in the MPSSE, so this bit-bangs the
+//! protocol over the same three ADBUS pins used for SPI, toggling pin
+//! direction to emulate the open-drain behaviour I2C requires (driving low
+//! by switching to output, releasing high by switching to input and relying
+//! on the bus pull-ups).
+
+use super::{Bank, FtInner, PinUse};
+use embedded_hal::blocking::i2c::{Read, Write, WriteRead};
+use libftd2xx::{FtdiMpsse, TimeoutError};
+use std::{cell::RefCell, sync::Mutex};
+
+const SCL: u8 = 0b0000_0001;
+const SDA: u8 = 0b0000_0110;
+
+/// FTDI I2C interface.
+///
+/// This is created by calling [`FtHal::i2c`].
+///
+/// Pin assignments:
+/// * AD0 => SCL
+/// * AD1 => SDA
+/// * AD2 => SDA
+///
+/// Yes, AD1 and AD2 are both SDA.
+/// These pins must be shorted together for I2C operation.
+///
+/// [`FtHal::i2c`]: crate::FtHal::i2c
+#[derive(Debug)]
+pub struct I2c<'a, DEVICE> {
+    mtx: &'a Mutex<RefCell<FtInner<DEVICE>>>,
+}
+
+impl<'a, DEVICE: FtdiMpsse> I2c<'a, DEVICE> {
+    pub(crate) fn new(
+        mtx: &'a Mutex<RefCell<FtInner<DEVICE>>>,
+    ) -> Result<I2c<'a, DEVICE>, TimeoutError> {
+        let lock = mtx.lock().expect("Failed to aquire FTDI mutex");
+        let mut inner = lock.borrow_mut();
+        inner.allocate_pin(Bank::Low, 0, PinUse::I2c);
+        inner.allocate_pin(Bank::Low, 1, PinUse::I2c);
+        inner.allocate_pin(Bank::Low, 2, PinUse::I2c);
+
+        // Idle bus: both SCL and SDA released (inputs, pulled high externally).
+        inner.direction[Bank::Low as usize] &= !(SCL | SDA);
+        inner.value[Bank::Low as usize] &= !(SCL | SDA);
+        inner.set_gpio(Bank::Low)?;
+
+        Ok(I2c { mtx })
+    }
+
+    fn scl(&self, inner: &mut FtInner<DEVICE>, high: bool) -> Result<(), TimeoutError> {
+        if high {
+            inner.direction[Bank::Low as usize] &= !SCL;
+        } else {
+            inner.direction[Bank::Low as usize] |= SCL;
+        }
+        inner.set_gpio(Bank::Low)
+    }
+
+    fn sda(&self, inner: &mut FtInner<DEVICE>, high: bool) -> Result<(), TimeoutError> {
+        if high {
+            inner.direction[Bank::Low as usize] &= !SDA;
+        } else {
+            inner.direction[Bank::Low as usize] |= SDA;
+        }
+        inner.set_gpio(Bank::Low)
+    }
+
+    fn read_sda(&self, inner: &mut FtInner<DEVICE>) -> Result<bool, TimeoutError> {
+        Ok(inner.gpio(Bank::Low)? & SDA != 0)
+    }
+
+    fn start(&self, inner: &mut FtInner<DEVICE>) -> Result<(), TimeoutError> {
+        self.sda(inner, true)?;
+        self.scl(inner, true)?;
+        self.sda(inner, false)?;
+        self.scl(inner, false)
+    }
+
+    fn stop(&self, inner: &mut FtInner<DEVICE>) -> Result<(), TimeoutError> {
+        self.sda(inner, false)?;
+        self.scl(inner, true)?;
+        self.sda(inner, true)
+    }
+
+    /// Clock a byte out MSB first, returning `true` if the slave acked.
+    fn write_byte(&self, inner: &mut FtInner<DEVICE>, byte: u8) -> Result<bool, TimeoutError> {
+        for bit in (0..8).rev() {
+            self.sda(inner, (byte >> bit) & 1 != 0)?;
+            self.scl(inner, true)?;
+            self.scl(inner, false)?;
+        }
+
+        self.sda(inner, true)?;
+        self.scl(inner, true)?;
+        let ack = !self.read_sda(inner)?;
+        self.scl(inner, false)?;
+        Ok(ack)
+    }
+
+    /// Clock a byte in MSB first, then send an ack (or nak for the final byte).
+    fn read_byte(&self, inner: &mut FtInner<DEVICE>, ack: bool) -> Result<u8, TimeoutError> {
+        self.sda(inner, true)?;
+        let mut byte = 0u8;
+        for _ in 0..8 {
+            self.scl(inner, true)?;
+            byte = (byte << 1) | u8::from(self.read_sda(inner)?);
+            self.scl(inner, false)?;
+        }
+
+        self.sda(inner, !ack)?;
+        self.scl(inner, true)?;
+        self.scl(inner, false)?;
+        Ok(byte)
+    }
+}
+
+impl<'a, DEVICE: FtdiMpsse> Write for I2c<'a, DEVICE> {
+    type Error = TimeoutError;
+
+    fn write(&mut self, address: u8, bytes: &[u8]) -> Result<(), Self::Error> {
+        let lock = self.mtx.lock().expect("Failed to aquire FTDI mutex");
+        let mut inner = lock.borrow_mut();
+
+        self.start(&mut inner)?;
+        self.write_byte(&mut inner, address << 1)?;
+        for byte in bytes {
+            self.write_byte(&mut inner, *byte)?;
+        }
+        self.stop(&mut inner)
+    }
+}
+
+impl<'a, DEVICE: FtdiMpsse> Read for I2c<'a, DEVICE> {
+    type Error = TimeoutError;
+
+    fn read(&mut self, address: u8, buffer: &mut [u8]) -> Result<(), Self::Error> {
+        let lock = self.mtx.lock().expect("Failed to aquire FTDI mutex");
+        let mut inner = lock.borrow_mut();
+
+        self.start(&mut inner)?;
+        self.write_byte(&mut inner, (address << 1) | 1)?;
+        let len = buffer.len();
+        for (i, byte) in buffer.iter_mut().enumerate() {
+            *byte = self.read_byte(&mut inner, i + 1 < len)?;
+        }
+        self.stop(&mut inner)
+    }
+}
+
+impl<'a, DEVICE: FtdiMpsse> WriteRead for I2c<'a, DEVICE> {
+    type Error = TimeoutError;
+
+    fn write_read(
+        &mut self,
+        address: u8,
+        bytes: &[u8],
+        buffer: &mut [u8],
+    ) -> Result<(), Self::Error> {
+        let lock = self.mtx.lock().expect("Failed to aquire FTDI mutex");
+        let mut inner = lock.borrow_mut();
+
+        self.start(&mut inner)?;
+        self.write_byte(&mut inner, address << 1)?;
+        for byte in bytes {
+            self.write_byte(&mut inner, *byte)?;
+        }
+
+        self.start(&mut inner)?;
+        self.write_byte(&mut inner, (address << 1) | 1)?;
+        let len = buffer.len();
+        for (i, byte) in buffer.iter_mut().enumerate() {
+            *byte = self.read_byte(&mut inner, i + 1 < len)?;
+        }
+        self.stop(&mut inner)
+    }
+}
+
+#[cfg(feature = "eh1")]
+impl<'a, DEVICE: FtdiMpsse> eh1::i2c::ErrorType for I2c<'a, DEVICE> {
+    type Error = crate::Eh1Error;
+}
+
+#[cfg(feature = "eh1")]
+impl<'a, DEVICE: FtdiMpsse> eh1::i2c::I2c for I2c<'a, DEVICE> {
+    fn transaction(
+        &mut self,
+        address: u8,
+        operations: &mut [eh1::i2c::Operation<'_>],
+    ) -> Result<(), Self::Error> {
+        let lock = self.mtx.lock().expect("Failed to aquire FTDI mutex");
+        let mut inner = lock.borrow_mut();
+
+        let count = operations.len();
+        // Whether each operation is a `Read`, computed up front so a `Read`
+        // can tell whether the *next* operation continues the same
+        // uninterrupted read run (in which case its own final byte is ACKed,
+        // and only the run's final byte gets the terminating NAK).
+        let readings: Vec<bool> = operations
+            .iter()
+            .map(|op| matches!(op, eh1::i2c::Operation::Read(_)))
+            .collect();
+        let mut prev_reading = None;
+        for (i, op) in operations.iter_mut().enumerate() {
+            let reading = readings[i];
+
+            // Only a (repeated) start and re-address are needed on the
+            // first operation and whenever the direction changes; adjacent
+            // operations of the same type are clocked back-to-back.
+            if prev_reading != Some(reading) {
+                self.start(&mut inner)?;
+                self.write_byte(&mut inner, (address << 1) | u8::from(reading))?;
+            }
+            prev_reading = Some(reading);
+
+            match op {
+                eh1::i2c::Operation::Write(bytes) => {
+                    for byte in bytes.iter() {
+                        self.write_byte(&mut inner, *byte)?;
+                    }
+                }
+                eh1::i2c::Operation::Read(buffer) => {
+                    // The read run continues past this buffer's last byte
+                    // if the next operation is also a `Read`; only the
+                    // final byte of the whole run gets NAK'd.
+                    let continues = readings.get(i + 1) == Some(&true);
+                    let len = buffer.len();
+                    for (j, byte) in buffer.iter_mut().enumerate() {
+                        let ack = j + 1 < len || continues;
+                        *byte = self.read_byte(&mut inner, ack)?;
+                    }
+                }
+            }
+
+            if i + 1 == count {
+                self.stop(&mut inner)?;
+            }
+        }
+
+        Ok(())
+    }
+}
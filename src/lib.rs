@@ -54,10 +54,28 @@
 //! # Ok::<(), std::boxed::Box<dyn std::error::Error>>(())
 //! ```
 //!
+//! # Multi-channel devices
+//!
+//! [`FtHal`] is generic over any libftd2xx device type that implements
+//! [`FtdiMpsse`] and [`FtdiCommon`], so a single physical FT2232H/FT4232H can
+//! drive independent protocols on each of its MPSSE channels. [`Ft232hHal`],
+//! [`Ft2232hHal`], and [`Ft4232hHal`] are type aliases of [`FtHal`] for the
+//! three supported chips.
+//!
+//! ```no_run
+//! use ftd2xx_embedded_hal::{Channel, Ft4232hHal};
+//!
+//! let a = Ft4232hHal::with_serial_number_channel("FT6ASGXH", Channel::A)?.init_default()?;
+//! let b = Ft4232hHal::with_serial_number_channel("FT6ASGXH", Channel::B)?.init_default()?;
+//! let spi = a.spi()?;
+//! let i2c = b.i2c()?;
+//! # Ok::<(), std::boxed::Box<dyn std::error::Error>>(())
+//! ```
+//!
 //! # Limitations
 //!
-//! * Limited trait support: SPI, I2C, Delay, and OutputPin traits are implemented.
-//! * Limited device support: FT232H.
+//! * Limited trait support: SPI, I2C, Delay, OutputPin, and InputPin traits are implemented.
+//! * Limited device support: FT232H, FT2232H, FT4232H.
 //!
 //! [embedded-hal]: https://crates.io/crates/embedded-hal
 //! [ftdi-embedded-hal]: https://github.com/geomatsi/ftdi-embedded-hal
@@ -70,19 +88,35 @@
 
 pub use embedded_hal;
 pub use libftd2xx;
+#[cfg(feature = "eh1")]
+pub use eh1;
 
 mod delay;
 mod gpio;
 mod i2c;
 mod spi;
+mod transaction;
+#[cfg(feature = "eh1")]
+mod eh1_error;
 
 pub use delay::Delay;
-pub use gpio::OutputPin;
+pub use gpio::{InputPin, OutputPin};
 pub use i2c::I2c;
-pub use spi::Spi;
+pub use spi::{Spi, SpiDevice};
+pub use transaction::Transaction;
+#[cfg(feature = "eh1")]
+pub use eh1_error::Error as Eh1Error;
 
-use libftd2xx::{DeviceTypeError, Ft232h, Ftdi, FtdiMpsse, MpsseSettings, TimeoutError};
-use std::{cell::RefCell, convert::TryInto, sync::Mutex, time::Duration};
+use libftd2xx::{
+    DeviceTypeError, Ft2232h, Ft232h, Ft4232h, Ftdi, FtdiCommon, FtdiMpsse, MpsseSettings,
+    TimeoutError,
+};
+use std::{
+    cell::RefCell,
+    convert::{TryFrom, TryInto},
+    sync::Mutex,
+    time::Duration,
+};
 
 /// State tracker for each pin on the FTDI chip.
 #[derive(Debug, Clone, Copy)]
@@ -90,6 +124,7 @@ enum PinUse {
     I2c,
     Spi,
     Output,
+    Input,
 }
 
 impl std::fmt::Display for PinUse {
@@ -98,45 +133,110 @@ impl std::fmt::Display for PinUse {
             PinUse::I2c => write!(f, "I2C"),
             PinUse::Spi => write!(f, "SPI"),
             PinUse::Output => write!(f, "GPIO"),
+            PinUse::Input => write!(f, "GPIO"),
+        }
+    }
+}
+
+/// Selects which byte-wide GPIO bank a pin belongs to.
+///
+/// Each MPSSE channel exposes its 16 GPIOs as two independent bytes, the
+/// lower ADBUS pins and the upper ACBUS pins, each with its own
+/// direction/value register in the MPSSE engine
+/// (`set_gpio_lower`/`set_gpio_upper`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Bank {
+    /// ADBUS, pins AD0-AD7.
+    Low,
+    /// ACBUS, pins AC0-AC7.
+    High,
+}
+
+/// Selects an MPSSE channel on a multi-channel FTDI device.
+///
+/// The FT232H has a single channel. The FT2232H has channels A and B. The
+/// FT4232H has channels A through D.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Channel {
+    /// Channel A.
+    A,
+    /// Channel B.
+    B,
+    /// Channel C, FT4232H only.
+    C,
+    /// Channel D, FT4232H only.
+    D,
+}
+
+impl Channel {
+    /// The interface letter libftd2xx appends to a device's serial number or
+    /// description to select this channel.
+    fn letter(self) -> char {
+        match self {
+            Channel::A => 'A',
+            Channel::B => 'B',
+            Channel::C => 'C',
+            Channel::D => 'D',
         }
     }
 }
 
 #[derive(Debug)]
-struct Ft232hInner {
+struct FtInner<DEVICE> {
     /// FTDI device.
-    ft: Ft232h,
-    /// GPIO direction.
-    direction: u8,
-    /// GPIO value.
-    value: u8,
-    /// Pin allocation.
-    pins: [Option<PinUse>; 8],
+    ft: DEVICE,
+    /// GPIO direction, indexed by [`Bank`].
+    direction: [u8; 2],
+    /// GPIO value, indexed by [`Bank`].
+    value: [u8; 2],
+    /// Pin allocation, indexed by [`Bank`].
+    pins: [[Option<PinUse>; 8]; 2],
 }
 
-impl Ft232hInner {
+impl<DEVICE> FtInner<DEVICE> {
     /// Allocate a pin for a specific use.
-    pub fn allocate_pin(&mut self, idx: u8, purpose: PinUse) {
+    pub fn allocate_pin(&mut self, bank: Bank, idx: u8, purpose: PinUse) {
         assert!(idx < 8, "Pin index {} is out of range 0 - 7", idx);
 
-        if let Some(current) = self.pins[usize::from(idx)] {
+        let pins = &mut self.pins[bank as usize];
+        if let Some(current) = pins[usize::from(idx)] {
             panic!(
                 "Unable to allocate pin {} for {}, pin is already allocated for {}",
                 idx, purpose, current
             );
         } else {
-            self.pins[usize::from(idx)] = Some(purpose)
+            pins[usize::from(idx)] = Some(purpose)
         }
     }
 }
 
-impl From<Ft232h> for Ft232hInner {
-    fn from(ft: Ft232h) -> Self {
-        Ft232hInner {
+impl<DEVICE: FtdiMpsse> FtInner<DEVICE> {
+    /// Push the direction/value registers for a single bank out to the device.
+    fn set_gpio(&mut self, bank: Bank) -> Result<(), TimeoutError> {
+        let direction = self.direction[bank as usize];
+        let value = self.value[bank as usize];
+        match bank {
+            Bank::Low => self.ft.set_gpio_lower(value, direction),
+            Bank::High => self.ft.set_gpio_upper(value, direction),
+        }
+    }
+
+    /// Read the current value of every pin in a bank.
+    fn gpio(&mut self, bank: Bank) -> Result<u8, TimeoutError> {
+        match bank {
+            Bank::Low => self.ft.gpio_lower(),
+            Bank::High => self.ft.gpio_upper(),
+        }
+    }
+}
+
+impl<DEVICE> From<DEVICE> for FtInner<DEVICE> {
+    fn from(ft: DEVICE) -> Self {
+        FtInner {
             ft,
-            direction: 0xFB,
-            value: 0x00,
-            pins: [None; 8],
+            direction: [0xFB, 0x00],
+            value: [0x00, 0x00],
+            pins: [[None; 8]; 2],
         }
     }
 }
@@ -155,16 +255,29 @@ pub struct Initialized;
 /// [rust-embedded book]: https://docs.rust-embedded.org/book/static-guarantees/design-contracts.html
 pub struct Uninitialized;
 
-/// FT232H device.
+/// Generic FTDI HAL, parameterized over the libftd2xx device type.
+///
+/// This is generic so that a single implementation covers every MPSSE
+/// capable chip and channel. Most users want one of the concrete aliases
+/// instead: [`Ft232hHal`], [`Ft2232hHal`], or [`Ft4232hHal`].
 #[derive(Debug)]
-pub struct Ft232hHal<INITIALIZED> {
+pub struct FtHal<DEVICE, INITIALIZED> {
     #[allow(dead_code)]
     init: INITIALIZED,
-    mtx: Mutex<RefCell<Ft232hInner>>,
+    mtx: Mutex<RefCell<FtInner<DEVICE>>>,
 }
 
-impl Ft232hHal<Uninitialized> {
-    /// Create a new FT232H structure.
+/// FT232H device, a single MPSSE channel.
+pub type Ft232hHal<INITIALIZED> = FtHal<Ft232h, INITIALIZED>;
+
+/// FT2232H device, channels A and B.
+pub type Ft2232hHal<INITIALIZED> = FtHal<Ft2232h, INITIALIZED>;
+
+/// FT4232H device, channels A through D.
+pub type Ft4232hHal<INITIALIZED> = FtHal<Ft4232h, INITIALIZED>;
+
+impl<DEVICE: FtdiCommon + TryFrom<Ftdi, Error = DeviceTypeError>> FtHal<DEVICE, Uninitialized> {
+    /// Create a new structure from the first available device of this type.
     ///
     /// # Example
     ///
@@ -174,12 +287,12 @@ impl Ft232hHal<Uninitialized> {
     /// let ftdi = hal::Ft232hHal::new()?.init_default()?;
     /// # Ok::<(), std::boxed::Box<dyn std::error::Error>>(())
     /// ```
-    pub fn new() -> Result<Ft232hHal<Uninitialized>, DeviceTypeError> {
-        let ft: Ft232h = Ftdi::new()?.try_into()?;
+    pub fn new() -> Result<FtHal<DEVICE, Uninitialized>, DeviceTypeError> {
+        let ft: DEVICE = Ftdi::new()?.try_into()?;
         Ok(ft.into())
     }
 
-    /// Create a new FT232H structure from a serial number.
+    /// Create a new structure from a serial number.
     ///
     /// # Example
     ///
@@ -189,28 +302,75 @@ impl Ft232hHal<Uninitialized> {
     /// let ftdi = hal::Ft232hHal::with_serial_number("FT6ASGXH")?.init_default()?;
     /// # Ok::<(), std::boxed::Box<dyn std::error::Error>>(())
     /// ```
-    pub fn with_serial_number(sn: &str) -> Result<Ft232hHal<Uninitialized>, DeviceTypeError> {
-        let ft: Ft232h = Ft232h::with_serial_number(sn)?;
+    pub fn with_serial_number(sn: &str) -> Result<FtHal<DEVICE, Uninitialized>, DeviceTypeError> {
+        let ft: DEVICE = Ftdi::with_serial_number(sn)?.try_into()?;
         Ok(ft.into())
     }
 
-    /// Open a `Ft4232h` device by its device description.
+    /// Open a device by its device description.
     ///
     /// # Example
     ///
     /// ```no_run
-    /// use libftd2xx::Ft4232h;
+    /// use ftd2xx_embedded_hal::Ft4232hHal;
     ///
-    /// Ft4232h::with_description("FT4232H-56Q MiniModule A")?;
-    /// # Ok::<(), libftd2xx::DeviceTypeError>(())
+    /// let ftdi = Ft4232hHal::with_description("FT4232H-56Q MiniModule A")?.init_default()?;
+    /// # Ok::<(), std::boxed::Box<dyn std::error::Error>>(())
     /// ```
     pub fn with_description(
         description: &str,
-    ) -> Result<Ft232hHal<Uninitialized>, DeviceTypeError> {
-        let ft: Ft232h = Ft232h::with_description(description)?;
+    ) -> Result<FtHal<DEVICE, Uninitialized>, DeviceTypeError> {
+        let ft: DEVICE = Ftdi::with_description(description)?.try_into()?;
         Ok(ft.into())
     }
 
+    /// Open a specific MPSSE channel of a multi-channel device by serial number.
+    ///
+    /// The channel letter is appended to `serial_number`, matching the way
+    /// libftd2xx enumerates each channel of a FT2232H/FT4232H as its own
+    /// device.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use ftd2xx_embedded_hal::{Channel, Ft4232hHal};
+    ///
+    /// let ftdi = Ft4232hHal::with_serial_number_channel("FT6ASGXH", Channel::A)?.init_default()?;
+    /// # Ok::<(), std::boxed::Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn with_serial_number_channel(
+        serial_number: &str,
+        channel: Channel,
+    ) -> Result<FtHal<DEVICE, Uninitialized>, DeviceTypeError> {
+        let sn = format!("{}{}", serial_number, channel.letter());
+        FtHal::with_serial_number(&sn)
+    }
+
+    /// Open a specific MPSSE channel of a multi-channel device by description.
+    ///
+    /// The channel letter is appended to `description`, e.g.
+    /// `"FT4232H-56Q MiniModule"` with [`Channel::A`] opens
+    /// `"FT4232H-56Q MiniModule A"`.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use ftd2xx_embedded_hal::{Channel, Ft4232hHal};
+    ///
+    /// let ftdi = Ft4232hHal::with_description_channel("FT4232H-56Q MiniModule", Channel::A)?
+    ///     .init_default()?;
+    /// # Ok::<(), std::boxed::Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn with_description_channel(
+        description: &str,
+        channel: Channel,
+    ) -> Result<FtHal<DEVICE, Uninitialized>, DeviceTypeError> {
+        let description = format!("{} {}", description, channel.letter());
+        FtHal::with_description(&description)
+    }
+}
+
+impl<DEVICE: FtdiMpsse + FtdiCommon> FtHal<DEVICE, Uninitialized> {
     /// Initialize the FTDI MPSSE with sane defaults.
     ///
     /// Default values:
@@ -232,7 +392,7 @@ impl Ft232hHal<Uninitialized> {
     /// let ftdi: Ft232hHal<Initialized> = ftdi.init_default()?;
     /// # Ok::<(), std::boxed::Box<dyn std::error::Error>>(())
     /// ```
-    pub fn init_default(self) -> Result<Ft232hHal<Initialized>, TimeoutError> {
+    pub fn init_default(self) -> Result<FtHal<DEVICE, Initialized>, TimeoutError> {
         const DEFAULT: MpsseSettings = MpsseSettings {
             reset: true,
             in_transfer_size: 4096,
@@ -277,24 +437,23 @@ impl Ft232hHal<Uninitialized> {
     pub fn init(
         self,
         mpsse_settings: &MpsseSettings,
-    ) -> Result<Ft232hHal<Initialized>, TimeoutError> {
+    ) -> Result<FtHal<DEVICE, Initialized>, TimeoutError> {
         {
             let lock = self.mtx.lock().expect("Failed to aquire FTDI mutex");
             let mut inner = lock.borrow_mut();
-            let mut settings = *mpsse_settings;
-            settings.mask = inner.direction;
-            inner.ft.initialize_mpsse(&mpsse_settings)?;
+            inner.ft.initialize_mpsse(mpsse_settings)?;
+            inner.set_gpio(Bank::High)?;
         }
 
-        Ok(Ft232hHal {
+        Ok(FtHal {
             init: Initialized,
             mtx: self.mtx,
         })
     }
 }
 
-impl From<Ft232h> for Ft232hHal<Uninitialized> {
-    /// Create a new FT232H structure from a specific FT232H device.
+impl<DEVICE> From<DEVICE> for FtHal<DEVICE, Uninitialized> {
+    /// Create a new structure from a specific, already-opened libftd2xx device.
     ///
     /// # Examples
     ///
@@ -321,16 +480,16 @@ impl From<Ft232h> for Ft232hHal<Uninitialized> {
     /// let ftdi = Ft232hHal::from(ft).init_default()?;
     /// # Ok::<(), std::boxed::Box<dyn std::error::Error>>(())
     /// ```
-    fn from(ft: Ft232h) -> Self {
-        Ft232hHal {
+    fn from(ft: DEVICE) -> Self {
+        FtHal {
             init: Uninitialized,
             mtx: Mutex::new(RefCell::new(ft.into())),
         }
     }
 }
 
-impl Ft232hHal<Initialized> {
-    /// Aquire the SPI peripheral for the FT232H.
+impl<DEVICE: FtdiMpsse + FtdiCommon> FtHal<DEVICE, Initialized> {
+    /// Aquire the SPI peripheral.
     ///
     /// Pin assignments:
     /// * AD0 => SCK
@@ -350,11 +509,37 @@ impl Ft232hHal<Initialized> {
     /// let mut spi = ftdi.spi()?;
     /// # Ok::<(), std::boxed::Box<dyn std::error::Error>>(())
     /// ```
-    pub fn spi(&self) -> Result<Spi, TimeoutError> {
+    pub fn spi(&self) -> Result<Spi<'_, DEVICE>, TimeoutError> {
         Spi::new(&self.mtx)
     }
 
-    /// Aquire the I2C peripheral for the FT232H.
+    /// Aquire the SPI peripheral, using a specific SPI mode.
+    ///
+    /// This selects the clock edges used to shift data in and out, and
+    /// drives AD0 (SCK) to the mode's idle polarity before the first
+    /// transfer, allowing devices that require modes 1-3 to be driven.
+    ///
+    /// Pin assignments are the same as [`FtHal::spi`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if pin 0, 1, or 2 are already in use.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use ftd2xx_embedded_hal as hal;
+    /// use hal::embedded_hal::spi::MODE_3;
+    ///
+    /// let ftdi = hal::Ft232hHal::new()?.init_default()?;
+    /// let mut spi = ftdi.spi_mode(MODE_3)?;
+    /// # Ok::<(), std::boxed::Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn spi_mode(&self, mode: embedded_hal::spi::Mode) -> Result<Spi<'_, DEVICE>, TimeoutError> {
+        Spi::with_mode(&self.mtx, mode)
+    }
+
+    /// Aquire the I2C peripheral.
     ///
     /// Pin assignments:
     /// * AD0 => SCL
@@ -377,79 +562,203 @@ impl Ft232hHal<Initialized> {
     /// let mut i2c = ftdi.i2c()?;
     /// # Ok::<(), std::boxed::Box<dyn std::error::Error>>(())
     /// ```
-    pub fn i2c(&self) -> Result<I2c, TimeoutError> {
+    pub fn i2c(&self) -> Result<I2c<'_, DEVICE>, TimeoutError> {
         I2c::new(&self.mtx)
     }
 
-    /// Aquire the digital output pin 0 for the FT232H.
+    /// Aquire the digital output pin 0 on the lower (ADBUS) byte.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the pin is already in-use.
+    pub fn ad0(&self) -> OutputPin<'_, DEVICE> {
+        OutputPin::new(&self.mtx, Bank::Low, 0)
+    }
+
+    /// Aquire the digital output pin 1 on the lower (ADBUS) byte.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the pin is already in-use.
+    pub fn ad1(&self) -> OutputPin<'_, DEVICE> {
+        OutputPin::new(&self.mtx, Bank::Low, 1)
+    }
+
+    /// Aquire the digital output pin 2 on the lower (ADBUS) byte.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the pin is already in-use.
+    pub fn ad2(&self) -> OutputPin<'_, DEVICE> {
+        OutputPin::new(&self.mtx, Bank::Low, 2)
+    }
+
+    /// Aquire the digital output pin 3 on the lower (ADBUS) byte.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the pin is already in-use.
+    pub fn ad3(&self) -> OutputPin<'_, DEVICE> {
+        OutputPin::new(&self.mtx, Bank::Low, 3)
+    }
+
+    /// Aquire the digital output pin 4 on the lower (ADBUS) byte.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the pin is already in-use.
+    pub fn ad4(&self) -> OutputPin<'_, DEVICE> {
+        OutputPin::new(&self.mtx, Bank::Low, 4)
+    }
+
+    /// Aquire the digital output pin 5 on the lower (ADBUS) byte.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the pin is already in-use.
+    pub fn ad5(&self) -> OutputPin<'_, DEVICE> {
+        OutputPin::new(&self.mtx, Bank::Low, 5)
+    }
+
+    /// Aquire the digital output pin 6 on the lower (ADBUS) byte.
     ///
     /// # Panics
     ///
     /// Panics if the pin is already in-use.
-    pub fn ad0(&self) -> OutputPin {
-        OutputPin::new(&self.mtx, 0)
+    pub fn ad6(&self) -> OutputPin<'_, DEVICE> {
+        OutputPin::new(&self.mtx, Bank::Low, 6)
     }
 
-    /// Aquire the digital output pin 1 for the FT232H.
+    /// Aquire the digital output pin 7 on the lower (ADBUS) byte.
     ///
     /// # Panics
     ///
     /// Panics if the pin is already in-use.
-    pub fn ad1(&self) -> OutputPin {
-        OutputPin::new(&self.mtx, 1)
+    pub fn ad7(&self) -> OutputPin<'_, DEVICE> {
+        OutputPin::new(&self.mtx, Bank::Low, 7)
     }
 
-    /// Aquire the digital output pin 2 for the FT232H.
+    /// Aquire the digital output pin 0 on the upper (ACBUS) byte.
     ///
     /// # Panics
     ///
     /// Panics if the pin is already in-use.
-    pub fn ad2(&self) -> OutputPin {
-        OutputPin::new(&self.mtx, 2)
+    pub fn ac0(&self) -> OutputPin<'_, DEVICE> {
+        OutputPin::new(&self.mtx, Bank::High, 0)
     }
 
-    /// Aquire the digital output pin 3 for the FT232H.
+    /// Aquire the digital output pin 1 on the upper (ACBUS) byte.
     ///
     /// # Panics
     ///
     /// Panics if the pin is already in-use.
-    pub fn ad3(&self) -> OutputPin {
-        OutputPin::new(&self.mtx, 3)
+    pub fn ac1(&self) -> OutputPin<'_, DEVICE> {
+        OutputPin::new(&self.mtx, Bank::High, 1)
     }
 
-    /// Aquire the digital output pin 4 for the FT232H.
+    /// Aquire the digital output pin 2 on the upper (ACBUS) byte.
     ///
     /// # Panics
     ///
     /// Panics if the pin is already in-use.
-    pub fn ad4(&self) -> OutputPin {
-        OutputPin::new(&self.mtx, 4)
+    pub fn ac2(&self) -> OutputPin<'_, DEVICE> {
+        OutputPin::new(&self.mtx, Bank::High, 2)
     }
 
-    /// Aquire the digital output pin 5 for the FT232H.
+    /// Aquire the digital output pin 3 on the upper (ACBUS) byte.
     ///
     /// # Panics
     ///
     /// Panics if the pin is already in-use.
-    pub fn ad5(&self) -> OutputPin {
-        OutputPin::new(&self.mtx, 5)
+    pub fn ac3(&self) -> OutputPin<'_, DEVICE> {
+        OutputPin::new(&self.mtx, Bank::High, 3)
     }
 
-    /// Aquire the digital output pin 6 for the FT232H.
+    /// Aquire the digital output pin 4 on the upper (ACBUS) byte.
     ///
     /// # Panics
     ///
     /// Panics if the pin is already in-use.
-    pub fn ad6(&self) -> OutputPin {
-        OutputPin::new(&self.mtx, 6)
+    pub fn ac4(&self) -> OutputPin<'_, DEVICE> {
+        OutputPin::new(&self.mtx, Bank::High, 4)
     }
 
-    /// Aquire the digital output pin 7 for the FT232H.
+    /// Aquire the digital output pin 5 on the upper (ACBUS) byte.
     ///
     /// # Panics
     ///
     /// Panics if the pin is already in-use.
-    pub fn ad7(&self) -> OutputPin {
-        OutputPin::new(&self.mtx, 7)
+    pub fn ac5(&self) -> OutputPin<'_, DEVICE> {
+        OutputPin::new(&self.mtx, Bank::High, 5)
+    }
+
+    /// Aquire the digital output pin 6 on the upper (ACBUS) byte.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the pin is already in-use.
+    pub fn ac6(&self) -> OutputPin<'_, DEVICE> {
+        OutputPin::new(&self.mtx, Bank::High, 6)
+    }
+
+    /// Aquire the digital output pin 7 on the upper (ACBUS) byte.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the pin is already in-use.
+    pub fn ac7(&self) -> OutputPin<'_, DEVICE> {
+        OutputPin::new(&self.mtx, Bank::High, 7)
+    }
+
+    /// Aquire digital input pin `idx` (0-7) on the lower (ADBUS) byte.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the pin is already in-use, or if `idx` is out of range 0-7.
+    pub fn ad_input(&self, idx: u8) -> InputPin<'_, DEVICE> {
+        InputPin::new(&self.mtx, Bank::Low, idx)
+    }
+
+    /// Aquire digital input pin `idx` (0-7) on the upper (ACBUS) byte.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the pin is already in-use, or if `idx` is out of range 0-7.
+    pub fn ac_input(&self, idx: u8) -> InputPin<'_, DEVICE> {
+        InputPin::new(&self.mtx, Bank::High, idx)
+    }
+
+    /// Run a batch of GPIO and SPI operations as a single USB transaction.
+    ///
+    /// Operations queued on the [`Transaction`] passed to `f` are collected
+    /// into one [`MpsseCmdBuilder`](libftd2xx::MpsseCmdBuilder) and sent to
+    /// the device as a single write, with any data clocked in read back in a
+    /// single read, instead of a USB round-trip per operation.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use ftd2xx_embedded_hal as hal;
+    ///
+    /// let ftdi = hal::Ft232hHal::new()?.init_default()?;
+    /// let cs = ftdi.ad3();
+    /// let mut read_buf = [0u8; 4];
+    /// ftdi.transaction(|tx| {
+    ///     tx.set_output(&cs, false);
+    ///     tx.spi_write(&[0x42]);
+    ///     tx.spi_read(&mut read_buf);
+    ///     tx.set_output(&cs, true);
+    /// })?;
+    /// # Ok::<(), std::boxed::Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn transaction<'b>(
+        &self,
+        f: impl FnOnce(&mut Transaction<'_, 'b, DEVICE>),
+    ) -> Result<(), TimeoutError> {
+        let lock = self.mtx.lock().expect("Failed to aquire FTDI mutex");
+        let mut inner = lock.borrow_mut();
+        let mut tx = Transaction::new(&mut inner);
+        f(&mut tx);
+        tx.commit()
     }
 }
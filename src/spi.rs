@@ -0,0 +1,220 @@
+//! SPI
+
+use super::{Bank, FtInner, OutputPin, PinUse};
+use embedded_hal::blocking::spi::{Transfer, Write};
+use embedded_hal::spi::{Mode, Phase, Polarity, MODE_0};
+use libftd2xx::{ClockData, ClockDataOut, FtdiMpsse, TimeoutError};
+use std::{cell::RefCell, sync::Mutex};
+
+/// Map an [`embedded_hal::spi::Mode`] onto the full-duplex [`ClockData`]
+/// variant that samples MISO on the correct edge.
+fn clock_data_for_mode(mode: Mode) -> ClockData {
+    match (mode.polarity, mode.phase) {
+        (Polarity::IdleLow, Phase::CaptureOnFirstTransition) => ClockData::MsbPosIn,
+        (Polarity::IdleLow, Phase::CaptureOnSecondTransition) => ClockData::MsbNegIn,
+        (Polarity::IdleHigh, Phase::CaptureOnFirstTransition) => ClockData::MsbNegIn,
+        (Polarity::IdleHigh, Phase::CaptureOnSecondTransition) => ClockData::MsbPosIn,
+    }
+}
+
+/// Map an [`embedded_hal::spi::Mode`] onto the [`ClockDataOut`] variant that
+/// shifts MOSI out on the correct edge.
+fn clock_data_out_for_mode(mode: Mode) -> ClockDataOut {
+    match (mode.polarity, mode.phase) {
+        (Polarity::IdleLow, Phase::CaptureOnFirstTransition) => ClockDataOut::MsbNeg,
+        (Polarity::IdleLow, Phase::CaptureOnSecondTransition) => ClockDataOut::MsbPos,
+        (Polarity::IdleHigh, Phase::CaptureOnFirstTransition) => ClockDataOut::MsbPos,
+        (Polarity::IdleHigh, Phase::CaptureOnSecondTransition) => ClockDataOut::MsbNeg,
+    }
+}
+
+/// FTDI SPI interface.
+///
+/// This is created by calling [`FtHal::spi`] or [`FtHal::spi_mode`].
+///
+/// Pin assignments:
+/// * AD0 => SCK
+/// * AD1 => MOSI
+/// * AD2 => MISO
+///
+/// [`FtHal::spi`]: crate::FtHal::spi
+/// [`FtHal::spi_mode`]: crate::FtHal::spi_mode
+#[derive(Debug)]
+pub struct Spi<'a, DEVICE> {
+    mtx: &'a Mutex<RefCell<FtInner<DEVICE>>>,
+    transfer_mode: ClockData,
+    write_mode: ClockDataOut,
+}
+
+impl<'a, DEVICE: FtdiMpsse> Spi<'a, DEVICE> {
+    pub(crate) fn new(
+        mtx: &'a Mutex<RefCell<FtInner<DEVICE>>>,
+    ) -> Result<Spi<'a, DEVICE>, TimeoutError> {
+        Spi::with_mode(mtx, MODE_0)
+    }
+
+    pub(crate) fn with_mode(
+        mtx: &'a Mutex<RefCell<FtInner<DEVICE>>>,
+        mode: Mode,
+    ) -> Result<Spi<'a, DEVICE>, TimeoutError> {
+        let lock = mtx.lock().expect("Failed to aquire FTDI mutex");
+        let mut inner = lock.borrow_mut();
+        inner.allocate_pin(Bank::Low, 0, PinUse::Spi);
+        inner.allocate_pin(Bank::Low, 1, PinUse::Spi);
+        inner.allocate_pin(Bank::Low, 2, PinUse::Spi);
+
+        // AD0 (SCK) and AD1 (MOSI) are outputs, AD2 (MISO) is an input.
+        inner.direction[Bank::Low as usize] |= 0b0000_0011;
+        inner.direction[Bank::Low as usize] &= !0b0000_0100;
+
+        // Idle AD0 (SCK) at the polarity the mode requires before the first
+        // clock pulse is ever issued.
+        if mode.polarity == Polarity::IdleHigh {
+            inner.value[Bank::Low as usize] |= 0b0000_0001;
+        } else {
+            inner.value[Bank::Low as usize] &= !0b0000_0001;
+        }
+
+        inner.set_gpio(Bank::Low)?;
+
+        Ok(Spi {
+            mtx,
+            transfer_mode: clock_data_for_mode(mode),
+            write_mode: clock_data_out_for_mode(mode),
+        })
+    }
+
+    pub(crate) fn mtx(&self) -> &'a Mutex<RefCell<FtInner<DEVICE>>> {
+        self.mtx
+    }
+
+    pub(crate) fn transfer_mode(&self) -> ClockData {
+        self.transfer_mode
+    }
+
+    pub(crate) fn write_mode(&self) -> ClockDataOut {
+        self.write_mode
+    }
+}
+
+impl<'a, DEVICE: FtdiMpsse> Transfer<u8> for Spi<'a, DEVICE> {
+    type Error = TimeoutError;
+
+    fn transfer<'w>(&mut self, words: &'w mut [u8]) -> Result<&'w [u8], Self::Error> {
+        let lock = self.mtx.lock().expect("Failed to aquire FTDI mutex");
+        let mut inner = lock.borrow_mut();
+        inner.ft.clock_data(self.transfer_mode, words)?;
+        Ok(words)
+    }
+}
+
+impl<'a, DEVICE: FtdiMpsse> Write<u8> for Spi<'a, DEVICE> {
+    type Error = TimeoutError;
+
+    fn write(&mut self, words: &[u8]) -> Result<(), Self::Error> {
+        let lock = self.mtx.lock().expect("Failed to aquire FTDI mutex");
+        let mut inner = lock.borrow_mut();
+        inner.ft.clock_data_out(self.write_mode, words)
+    }
+}
+
+/// An [`Spi`] bus paired with a dedicated chip-select pin.
+///
+/// Asserting chip-select, performing the transfer, and de-asserting
+/// chip-select happen under a single mutex lock, so multiple `SpiDevice`s
+/// built from the same `&Spi` can share one bus safely: `Spi` is only ever
+/// created once per channel (it allocates AD0-AD2), so sharing devices
+/// borrow it rather than taking ownership of it.
+#[derive(Debug)]
+pub struct SpiDevice<'b, 'a, DEVICE> {
+    spi: &'b Spi<'a, DEVICE>,
+    cs: OutputPin<'a, DEVICE>,
+}
+
+impl<'b, 'a, DEVICE: FtdiMpsse> SpiDevice<'b, 'a, DEVICE> {
+    /// Pair `spi` with `cs`, a pin driven low for the duration of each transfer.
+    ///
+    /// `spi` is borrowed, not consumed, so the same bus can be paired with
+    /// several chip-select pins to build several `SpiDevice`s.
+    pub fn new(spi: &'b Spi<'a, DEVICE>, cs: OutputPin<'a, DEVICE>) -> SpiDevice<'b, 'a, DEVICE> {
+        SpiDevice { spi, cs }
+    }
+
+    /// Assert chip-select, run `f` with the locked device, then de-assert
+    /// chip-select, all while holding the FTDI mutex.
+    fn with_cs_asserted<T>(
+        &mut self,
+        f: impl FnOnce(&mut FtInner<DEVICE>) -> Result<T, TimeoutError>,
+    ) -> Result<T, TimeoutError> {
+        let lock = self.spi.mtx().lock().expect("Failed to aquire FTDI mutex");
+        let mut inner = lock.borrow_mut();
+        let bank = self.cs.bank();
+        let idx = self.cs.idx();
+
+        inner.value[bank as usize] &= !(1 << idx);
+        inner.set_gpio(bank)?;
+
+        let result = f(&mut inner);
+
+        inner.value[bank as usize] |= 1 << idx;
+        inner.set_gpio(bank)?;
+
+        result
+    }
+}
+
+impl<'b, 'a, DEVICE: FtdiMpsse> Transfer<u8> for SpiDevice<'b, 'a, DEVICE> {
+    type Error = TimeoutError;
+
+    fn transfer<'w>(&mut self, words: &'w mut [u8]) -> Result<&'w [u8], Self::Error> {
+        let transfer_mode = self.spi.transfer_mode();
+        self.with_cs_asserted(|inner| inner.ft.clock_data(transfer_mode, words))?;
+        Ok(words)
+    }
+}
+
+impl<'b, 'a, DEVICE: FtdiMpsse> Write<u8> for SpiDevice<'b, 'a, DEVICE> {
+    type Error = TimeoutError;
+
+    fn write(&mut self, words: &[u8]) -> Result<(), Self::Error> {
+        let write_mode = self.spi.write_mode();
+        self.with_cs_asserted(|inner| inner.ft.clock_data_out(write_mode, words))
+    }
+}
+
+#[cfg(feature = "eh1")]
+impl<'a, DEVICE: FtdiMpsse> eh1::spi::ErrorType for Spi<'a, DEVICE> {
+    type Error = crate::Eh1Error;
+}
+
+#[cfg(feature = "eh1")]
+impl<'a, DEVICE: FtdiMpsse> eh1::spi::SpiBus<u8> for Spi<'a, DEVICE> {
+    fn read(&mut self, words: &mut [u8]) -> Result<(), Self::Error> {
+        Transfer::transfer(self, words).map(drop).map_err(Into::into)
+    }
+
+    fn write(&mut self, words: &[u8]) -> Result<(), Self::Error> {
+        Ok(Write::write(self, words)?)
+    }
+
+    fn transfer(&mut self, read: &mut [u8], write: &[u8]) -> Result<(), Self::Error> {
+        // The MPSSE clocks MOSI and MISO simultaneously, so stage the full
+        // `max(read, write)` words to clock: bytes past the end of `write`
+        // are padded with zero, bytes past the end of `read` are clocked
+        // in and discarded.
+        let len = read.len().max(write.len());
+        let mut buf = vec![0u8; len];
+        buf[..write.len()].copy_from_slice(write);
+        Transfer::transfer(self, &mut buf)?;
+        read.copy_from_slice(&buf[..read.len()]);
+        Ok(())
+    }
+
+    fn transfer_in_place(&mut self, words: &mut [u8]) -> Result<(), Self::Error> {
+        Transfer::transfer(self, words).map(drop).map_err(Into::into)
+    }
+
+    fn flush(&mut self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+}
@@ -0,0 +1,86 @@
+//! Batched MPSSE transactions.
+//!
+//! A [`Transaction`] accumulates GPIO and SPI operations into a single
+//! [`MpsseCmdBuilder`] so that they reach the device as one USB write, and
+//! any data clocked in is read back with one USB read, instead of paying a
+//! round-trip per operation.
+
+use super::{Bank, FtInner, OutputPin};
+use libftd2xx::{ClockDataIn, ClockDataOut, FtdiCommon, FtdiMpsse, MpsseCmdBuilder, TimeoutError};
+
+/// A batched sequence of GPIO and SPI operations.
+///
+/// This is created by [`FtHal::transaction`].
+///
+/// [`FtHal::transaction`]: crate::FtHal::transaction
+pub struct Transaction<'a, 'b, DEVICE> {
+    inner: &'a mut FtInner<DEVICE>,
+    cmd: MpsseCmdBuilder,
+    reads: Vec<&'b mut [u8]>,
+}
+
+impl<'a, 'b, DEVICE: FtdiMpsse + FtdiCommon> Transaction<'a, 'b, DEVICE> {
+    pub(crate) fn new(inner: &'a mut FtInner<DEVICE>) -> Self {
+        Transaction {
+            inner,
+            cmd: MpsseCmdBuilder::new(),
+            reads: Vec::new(),
+        }
+    }
+
+    /// Queue driving `pin` high or low.
+    pub fn set_output(&mut self, pin: &OutputPin<DEVICE>, high: bool) {
+        let bank = pin.bank();
+        if high {
+            self.inner.value[bank as usize] |= 1 << pin.idx();
+        } else {
+            self.inner.value[bank as usize] &= !(1 << pin.idx());
+        }
+
+        let direction = self.inner.direction[bank as usize];
+        let value = self.inner.value[bank as usize];
+        let cmd = std::mem::replace(&mut self.cmd, MpsseCmdBuilder::new());
+        self.cmd = match bank {
+            Bank::Low => cmd.set_gpio_lower(value, direction),
+            Bank::High => cmd.set_gpio_upper(value, direction),
+        };
+    }
+
+    /// Queue clocking `data` out over SPI (mode 0, MSB first).
+    pub fn spi_write(&mut self, data: &[u8]) {
+        let cmd = std::mem::replace(&mut self.cmd, MpsseCmdBuilder::new());
+        self.cmd = cmd.clock_data_out(ClockDataOut::MsbNeg, data);
+    }
+
+    /// Queue clocking `buf.len()` bytes in over SPI (mode 0, MSB first).
+    ///
+    /// `buf` is filled in with the received bytes once the transaction is
+    /// flushed by [`FtHal::transaction`](crate::FtHal::transaction).
+    pub fn spi_read(&mut self, buf: &'b mut [u8]) {
+        let cmd = std::mem::replace(&mut self.cmd, MpsseCmdBuilder::new());
+        self.cmd = cmd.clock_data_in(ClockDataIn::MsbPos, buf.len());
+        self.reads.push(buf);
+    }
+
+    /// Flush the accumulated commands to the device in a single write, and
+    /// scatter any data clocked in back into the caller's read buffers.
+    pub(crate) fn commit(self) -> Result<(), TimeoutError> {
+        let cmd = self.cmd.send_immediate();
+        self.inner.ft.write_all(cmd.as_slice())?;
+
+        let total: usize = self.reads.iter().map(|buf| buf.len()).sum();
+        if total > 0 {
+            let mut raw = vec![0u8; total];
+            self.inner.ft.read_all(&mut raw)?;
+
+            let mut offset = 0;
+            for buf in self.reads {
+                let len = buf.len();
+                buf.copy_from_slice(&raw[offset..offset + len]);
+                offset += len;
+            }
+        }
+
+        Ok(())
+    }
+}